@@ -13,12 +13,20 @@ use smithay_client_toolkit::{
         wl_output::WlOutput,
         wl_region::WlRegion,
         wl_shm::Format,
+        wl_subsurface::{self, WlSubsurface},
+        wl_surface::WlSurface,
       },
-      Connection, Dispatch, QueueHandle,
+      Connection, Dispatch, EventQueue, QueueHandle,
     },
-    protocols::wp::viewporter::client::{
-      wp_viewport::{self, WpViewport},
-      wp_viewporter::{self, WpViewporter},
+    protocols::wp::{
+      fractional_scale::client::{
+        wp_fractional_scale_manager_v1::{self, WpFractionalScaleManagerV1},
+        wp_fractional_scale_v1::{self, WpFractionalScaleV1},
+      },
+      viewporter::client::{
+        wp_viewport::{self, WpViewport},
+        wp_viewporter::{self, WpViewporter},
+      },
     },
   },
   registry::{ProvidesRegistryState, RegistryState, SimpleGlobal},
@@ -28,21 +36,34 @@ use smithay_client_toolkit::{
     WaylandSurface,
   },
   shm::{raw::RawPool, Shm, ShmHandler},
+  subcompositor::SubcompositorState,
 };
+use serde::{Deserialize, Serialize};
 use std::{
+  collections::HashMap,
   env,
   process::{Command, Stdio},
   sync::{Arc, Condvar, Mutex},
 };
 use std::{fs, sync::Once};
 use std::{
-  io::{BufRead, BufReader, Write},
+  io::{self, BufReader, Read, Write},
   os::unix::net::{UnixListener, UnixStream},
   process, thread,
 };
 
 const DEFAULT_ALPHA: f32 = 0.5;
 const DEFAULT_RADIUS: u32 = 0;
+const DEFAULT_FADE_MS: u32 = 200;
+const DEFAULT_COLOR: &str = "000000";
+// wp_fractional_scale_v1 reports scale as an integer multiplied by 120;
+// this is the "1.0x" baseline used until a preferred_scale event (or a
+// legacy integer scale_factor_changed callback) says otherwise.
+const DEFAULT_SCALE_120: u32 = 120;
+// Real `IpcRequest`/`DaemonStatus` payloads are a few hundred bytes at
+// most - cap the declared frame length well above that but far below
+// what a malformed or adversarial write could force us to allocate.
+const MAX_IPC_FRAME_BYTES: usize = 64 * 1024;
 
 static mut QH: Option<QueueHandle<DimlandData>> = None;
 static QH_INIT: Once = Once::new();
@@ -54,19 +75,27 @@ lazy_static! {
     alpha: Some(DEFAULT_ALPHA),
     allow_opaque: false,
     radius: Some(DEFAULT_RADIUS),
-    output: None,
+    fade_ms: Some(DEFAULT_FADE_MS),
+    color: Some(DEFAULT_COLOR.to_owned()),
+    output: Vec::new(),
     command: None,
     detached: false
   });
+  static ref OUTPUT_OVERRIDES: Mutex<HashMap<String, OutputOverride>> = Mutex::new(HashMap::new());
+  // Snapshot read by `IpcRequest::Query`, refreshed by `DimlandData::publish_status`
+  // whenever a view's alpha/radius/fade state or the output set changes.
+  static ref STATUS: Mutex<DaemonStatus> = Mutex::new(DaemonStatus::default());
 }
 
-#[derive(Debug, Subcommand, Clone)]
+#[derive(Debug, Subcommand, Clone, Serialize, Deserialize)]
 enum DimlandCommands {
   /// Stops the program
   Stop,
+  /// Prints the running daemon's current per-output state
+  Query,
 }
 
-#[derive(Debug, Parser, Clone)]
+#[derive(Debug, Parser, Clone, Serialize, Deserialize)]
 #[command(version)]
 struct DimlandArgs {
   #[arg(
@@ -83,33 +112,212 @@ struct DimlandArgs {
     help = format!("Corner radius (default {DEFAULT_RADIUS})")
   )]
   radius: Option<u32>,
-  #[arg(short, long, help = "Output to control (ex. DP-1)")]
-  output: Option<String>,
+  #[arg(
+    long,
+    help = format!("Fade duration in milliseconds for alpha transitions (default {DEFAULT_FADE_MS})")
+  )]
+  fade_ms: Option<u32>,
+  #[arg(
+    short,
+    long,
+    help = format!("Dim color as RRGGBB hex, for a tint/night-light effect (default {DEFAULT_COLOR})")
+  )]
+  color: Option<String>,
+  #[arg(
+    short,
+    long,
+    help = "Per-output override, repeatable (ex. -o DP-1:alpha=0.3:radius=20 -o HDMI-A-1:alpha=0.6)"
+  )]
+  output: Vec<String>,
   #[arg(short, long, hide = true)]
   detached: bool,
   #[command(subcommand)]
   command: Option<DimlandCommands>,
 }
 
+// A single message on the IPC socket. `Apply` carries a freshly-parsed
+// `DimlandArgs` from a client invocation, `Stop` and `Query` are bare
+// commands. Framed with `write_frame`/`read_frame` below instead of the
+// old "stop" literal / raw argv line.
+#[derive(Debug, Serialize, Deserialize)]
+enum IpcRequest {
+  Apply(DimlandArgs),
+  Stop,
+  Query,
+}
+
+// Per-output state reported back on `IpcRequest::Query`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OutputStatus {
+  name: String,
+  alpha: f32,
+  radius: u32,
+  color: String,
+  fading: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct DaemonStatus {
+  outputs: Vec<OutputStatus>,
+}
+
+// Writes `value` as a length-prefixed JSON frame: a u32 LE byte count
+// followed by exactly that many payload bytes. Unix sockets aren't
+// subject to Nagle-style coalescing the way TCP is, but the frame is
+// still built up front and sent with a single `write_all` so a reply
+// can never be observed half-written on the other end.
+fn write_frame<T: Serialize>(stream: &mut impl Write, value: &T) -> io::Result<()> {
+  let payload =
+    serde_json::to_vec(value).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+  let mut frame = Vec::with_capacity(4 + payload.len());
+  frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+  frame.extend_from_slice(&payload);
+  stream.write_all(&frame)
+}
+
+// Reads one length-prefixed JSON frame. Returns `Ok(None)` on a clean EOF
+// before any bytes arrive, which happens whenever a client disconnects
+// without writing anything.
+fn read_frame<T: for<'de> Deserialize<'de>>(reader: &mut impl Read) -> io::Result<Option<T>> {
+  let mut len_bytes = [0u8; 4];
+  if let Err(err) = reader.read_exact(&mut len_bytes) {
+    return if err.kind() == io::ErrorKind::UnexpectedEof {
+      Ok(None)
+    } else {
+      Err(err)
+    };
+  }
+
+  let len = u32::from_le_bytes(len_bytes) as usize;
+  if len > MAX_IPC_FRAME_BYTES {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidData,
+      format!("IPC frame of {len} bytes exceeds the {MAX_IPC_FRAME_BYTES} byte limit"),
+    ));
+  }
+
+  let mut payload = vec![0u8; len];
+  reader.read_exact(&mut payload)?;
+  serde_json::from_slice(&payload)
+    .map(Some)
+    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
 fn get_socket_path() -> String {
   let xdg_runtime_dir = env::var("XDG_RUNTIME_DIR").expect("XDG_RUNTIME_DIR not set");
   format!("{}/dimland.sock", xdg_runtime_dir)
 }
 
+// A per-output `alpha`/`radius` override, parsed from a `-o` spec like
+// `DP-1:alpha=0.3:radius=20`. Unset fields fall back to the global
+// `--alpha`/`--radius` when rendering that output.
+#[derive(Debug, Clone, Copy, Default)]
+struct OutputOverride {
+  alpha: Option<f32>,
+  radius: Option<u32>,
+}
+
+// Parses a single `-o` spec into (output name, override). Unknown keys and
+// unparsable values are silently ignored. `alpha` is clamped the same
+// way as the global `--alpha`, so a per-output override can't produce an
+// out-of-range value that corrupts `premultiplied_argb`'s packing.
+fn parse_output_override(spec: &str, allow_opaque: bool) -> Option<(String, OutputOverride)> {
+  let mut parts = spec.split(':');
+  let name = parts.next()?;
+  if name.is_empty() {
+    return None;
+  }
+
+  let mut over = OutputOverride::default();
+  for part in parts {
+    let Some((key, value)) = part.split_once('=') else {
+      continue;
+    };
+    match key {
+      "alpha" => {
+        over.alpha = value
+          .parse()
+          .ok()
+          .map(|alpha| clamp_alpha(alpha, allow_opaque))
+      }
+      "radius" => over.radius = value.parse().ok(),
+      _ => {}
+    }
+  }
+
+  Some((name.to_owned(), over))
+}
+
+fn get_output_override(name: &str) -> Option<OutputOverride> {
+  OUTPUT_OVERRIDES.lock().unwrap().get(name).copied()
+}
+
+// Parses an `RRGGBB` hex string into its components. Invalid input is
+// ignored by the caller, the same way an invalid `-o` spec is.
+fn parse_color(hex: &str) -> Option<(u8, u8, u8)> {
+  if hex.len() != 6 || !hex.is_ascii() {
+    return None;
+  }
+
+  let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+  let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+  let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+  Some((r, g, b))
+}
+
+// Clamps/sanitizes a user-supplied alpha the same way for both the
+// global `--alpha` and per-output `-o NAME:alpha=...` overrides.
+// `f32::from_str` happily accepts "nan"/"inf", and any comparison against
+// NaN is false, so a plain `> 0.9` check wouldn't catch it - fall back to
+// the default instead of letting a non-finite alpha reach
+// rendering/serialization.
+fn clamp_alpha(alpha: f32, allow_opaque: bool) -> f32 {
+  if !alpha.is_finite() {
+    DEFAULT_ALPHA
+  } else if !allow_opaque && alpha > 0.9 {
+    0.9
+  } else {
+    alpha.clamp(0.0, 1.0)
+  }
+}
+
 fn set_args(args: DimlandArgs) {
   let mut args_ref = ARGS.lock().unwrap();
 
   // Only update newly provided arguments,
   // otherwise keep previous arguments
-  if let Some(mut alpha) = args.alpha {
-    if !args.allow_opaque {
-      alpha = if alpha > 0.9 { 0.9 } else { alpha };
-    }
-    args_ref.alpha = Some(alpha);
+  if let Some(alpha) = args.alpha {
+    args_ref.alpha = Some(clamp_alpha(alpha, args.allow_opaque));
   }
   if let Some(radius) = args.radius {
     args_ref.radius = Some(radius);
   }
+  if let Some(fade_ms) = args.fade_ms {
+    args_ref.fade_ms = Some(fade_ms);
+  }
+  if let Some(color) = args.color {
+    args_ref.color = Some(color);
+  }
+
+  // Output overrides are upserted by name into a persistent table instead
+  // of being kept as a raw list, so a later invocation that only touches
+  // one output doesn't clobber the others - and merged field-by-field into
+  // any existing entry, so a later `-o NAME:alpha=...` with no `radius=`
+  // doesn't reset that output's radius override either.
+  if !args.output.is_empty() {
+    let mut overrides = OUTPUT_OVERRIDES.lock().unwrap();
+    for spec in &args.output {
+      if let Some((name, over)) = parse_output_override(spec, args.allow_opaque) {
+        let entry = overrides.entry(name).or_default();
+        if over.alpha.is_some() {
+          entry.alpha = over.alpha;
+        }
+        if over.radius.is_some() {
+          entry.radius = over.radius;
+        }
+      }
+    }
+  }
 
   args_ref.output = args.output;
   args_ref.command = args.command;
@@ -139,19 +347,43 @@ fn main() {
 
   match args.command {
     Some(DimlandCommands::Stop) => {
-      match UnixStream::connect(socket_path) {
-        Ok(mut stream) => stream.write_all("stop".as_bytes()).unwrap(),
-        _ => (),
-      };
+      if let Ok(mut stream) = UnixStream::connect(&socket_path) {
+        let _ = write_frame(&mut stream, &IpcRequest::Stop);
+      }
+      process::exit(0);
+    }
+    Some(DimlandCommands::Query) => {
+      match UnixStream::connect(&socket_path) {
+        Ok(mut stream) => {
+          if let Err(err) = write_frame(&mut stream, &IpcRequest::Query) {
+            eprintln!("Error sending IPC message: {}", err);
+            process::exit(1);
+          }
+          match read_frame::<DaemonStatus>(&mut stream) {
+            Ok(Some(status)) if status.outputs.is_empty() => {
+              println!("dimland is running, no outputs yet");
+            }
+            Ok(Some(status)) => {
+              for output in status.outputs {
+                println!(
+                  "{}: alpha={:.2} radius={} color={} fading={}",
+                  output.name, output.alpha, output.radius, output.color, output.fading
+                );
+              }
+            }
+            _ => eprintln!("Error reading response from dimland daemon"),
+          }
+        }
+        Err(_) => eprintln!("dimland is not running"),
+      }
       process::exit(0);
     }
     _ => (),
   }
 
-  match UnixStream::connect(socket_path) {
+  match UnixStream::connect(&socket_path) {
     Ok(mut stream) => {
-      let message = env::args().collect::<Vec<String>>().join(" ");
-      if let Err(err) = stream.write_all(message.as_bytes()) {
+      if let Err(err) = write_frame(&mut stream, &IpcRequest::Apply(DimlandArgs::parse())) {
         eprintln!("Error sending IPC message: {}", err);
       }
       process::exit(0);
@@ -201,35 +433,51 @@ fn listen_for_ipc() {
 }
 
 fn handle_ipc(stream: UnixStream) {
+  let mut writer = match stream.try_clone() {
+    Ok(cloned) => cloned,
+    Err(err) => {
+      eprintln!("Error cloning IPC stream: {}", err);
+      return;
+    }
+  };
   let mut reader = BufReader::new(stream);
-  let mut message = String::new();
 
-  match reader.read_line(&mut message) {
-    Ok(_) => {
-      if message == "stop" {
-        cleanup();
-        process::exit(0);
+  match read_frame::<IpcRequest>(&mut reader) {
+    Ok(Some(IpcRequest::Stop)) => {
+      // Route through the regular args/command pipeline instead of
+      // exiting this thread immediately, so the event loop gets a
+      // chance to fade the overlay out before the process exits.
+      set_args(DimlandArgs {
+        alpha: None,
+        allow_opaque: false,
+        radius: None,
+        fade_ms: None,
+        color: None,
+        output: Vec::new(),
+        command: Some(DimlandCommands::Stop),
+        detached: false,
+      });
+      let (lock, cvar) = &**FLAG;
+      let mut flag_guard = lock.lock().unwrap();
+      *flag_guard = true;
+      cvar.notify_one();
+    }
+    Ok(Some(IpcRequest::Query)) => {
+      let status = STATUS.lock().unwrap().clone();
+      if let Err(err) = write_frame(&mut writer, &status) {
+        eprintln!("Error sending IPC reply: {}", err);
       }
-
-      let args: Vec<String> = message
-        .trim()
-        .split_whitespace()
-        .map(String::from)
-        .collect();
-
-      match DimlandArgs::try_parse_from(args) {
-        Ok(args) => {
-          set_args(args);
-          let (lock, cvar) = &**FLAG;
-          let mut flag_guard = lock.lock().unwrap();
-          *flag_guard = true;
-          cvar.notify_one();
-        }
-        _ => (),
-      };
     }
+    Ok(Some(IpcRequest::Apply(args))) => {
+      set_args(args);
+      let (lock, cvar) = &**FLAG;
+      let mut flag_guard = lock.lock().unwrap();
+      *flag_guard = true;
+      cvar.notify_one();
+    }
+    Ok(None) => {}
     Err(err) => {
-      eprintln!("Error reading message: {}", err);
+      eprintln!("Error reading IPC message: {}", err);
     }
   }
 }
@@ -265,8 +513,15 @@ fn _main() {
 
   let alpha = args.alpha.unwrap_or(DEFAULT_ALPHA);
   let radius = args.radius.unwrap_or(DEFAULT_RADIUS);
+  let fade_ms = args.fade_ms.unwrap_or(DEFAULT_FADE_MS);
+  let color = args
+    .color
+    .and_then(|color| parse_color(&color))
+    .unwrap_or_else(|| parse_color(DEFAULT_COLOR).unwrap());
 
-  let mut data = DimlandData::new(compositor, &globals, &qh, layer_shell, alpha, radius, shm);
+  let mut data = DimlandData::new(
+    compositor, &globals, &qh, layer_shell, alpha, radius, fade_ms, color, shm,
+  );
 
   let mut i = 0;
   loop {
@@ -275,8 +530,17 @@ fn _main() {
     if i > 10 {
       block_until_event();
       let new_args = get_args();
+
+      if let Some(DimlandCommands::Stop) = new_args.command {
+        data.fade_out_and_exit(&mut event_queue);
+      }
+
       data.alpha = new_args.alpha.unwrap_or(DEFAULT_ALPHA);
       data.radius = new_args.radius.unwrap_or(DEFAULT_RADIUS);
+      data.fade_ms = new_args.fade_ms.unwrap_or(DEFAULT_FADE_MS);
+      if let Some(color) = new_args.color.and_then(|color| parse_color(&color)) {
+        data.color = color;
+      }
       data.rerender();
     } else {
       i += 1;
@@ -299,8 +563,12 @@ struct DimlandData {
   output_state: OutputState,
   layer_shell: LayerShell,
   viewporter: SimpleGlobal<WpViewporter, 1>,
+  subcompositor: SubcompositorState,
+  fractional_scale_manager: Option<WpFractionalScaleManagerV1>,
   alpha: f32,
   radius: u32,
+  fade_ms: u32,
+  color: (u8, u8, u8),
   views: Vec<DimlandView>,
   exit: bool,
   shm: Shm,
@@ -317,70 +585,155 @@ struct DimlandView {
   first_configure: bool,
   width: u32,
   height: u32,
+  radius: u32,
+  // Color actually baked into `buffer`/`corners` as of the last
+  // `apply_alpha` call, so `rerender` can tell a `--color` change apart
+  // from a no-op.
+  rendered_color: (u8, u8, u8),
+  current_alpha: f32,
+  fade: Option<FadeState>,
   buffer: WlBuffer,
   viewport: WpViewport,
   layer: LayerSurface,
   output: WlOutput,
+  corners: Vec<DimlandCorner>,
+  // Device scale in 120ths (wp_fractional_scale_v1's units), e.g. 180 for
+  // 1.5x. Kept at DEFAULT_SCALE_120 until a preferred_scale event, or a
+  // scale_factor_changed fallback, reports the real value.
+  scale_120: u32,
+  fractional_scale: Option<WpFractionalScaleV1>,
 }
 
+// In-flight alpha transition for a view. `start_time` is filled in lazily
+// from the first frame callback's `time` argument, since that's the only
+// clock the compositor gives us for pacing animations.
+#[derive(Debug, Clone, Copy)]
+struct FadeState {
+  from: f32,
+  to: f32,
+  start_time: Option<u32>,
+  duration_ms: u32,
+}
+
+fn ease_out_cubic(t: f32) -> f32 {
+  1.0 - (1.0 - t).powi(3)
+}
+
+// A corner of the output that isn't covered by the stretched 1x1 center
+// buffer, rendered at native `radius x radius` resolution and stacked on
+// top of the layer surface as its own subsurface.
+struct DimlandCorner {
+  surface: WlSurface,
+  subsurface: WlSubsurface,
+  buffer: WlBuffer,
+  viewport: WpViewport,
+}
+
+// Scales a logical (surface-local) dimension up to the device-pixel
+// resolution a buffer should be allocated at, given a wp_fractional_scale
+// style scale factor in 120ths.
+fn scale_to_device(logical: u32, scale_120: u32) -> u32 {
+  ((logical as u64 * scale_120 as u64 + 119) / 120) as u32
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Corner {
+  TopLeft,
+  TopRight,
+  BottomLeft,
+  BottomRight,
+}
+
+fn corner_positions(width: u32, height: u32, radius: u32) -> [(Corner, i32, i32); 4] {
+  [
+    (Corner::TopLeft, 0, 0),
+    (Corner::TopRight, (width.saturating_sub(radius)) as i32, 0),
+    (Corner::BottomLeft, 0, (height.saturating_sub(radius)) as i32),
+    (
+      Corner::BottomRight,
+      (width.saturating_sub(radius)) as i32,
+      (height.saturating_sub(radius)) as i32,
+    ),
+  ]
+}
+
+// Argb8888 is premultiplied alpha, so each channel has to be scaled by the
+// alpha factor before packing or the compositor will blend it too bright.
+fn premultiplied_argb(alpha_byte: u32, color: (u8, u8, u8)) -> u32 {
+  let r = (color.0 as u32 * alpha_byte) / 255;
+  let g = (color.1 as u32 * alpha_byte) / 255;
+  let b = (color.2 as u32 * alpha_byte) / 255;
+  (alpha_byte << 24) | (r << 16) | (g << 8) | b
+}
+
+// The center of the overlay is a flat fill, so a single 1x1 Argb8888 pixel
+// stretched over the whole output via the viewporter is enough: this turns
+// a `width * height * 4` byte allocation (and refill) into 4 bytes, and
+// every rerender into an O(1) pixel write instead of a full-buffer loop.
 fn create_buffer(
   alpha: f32,
+  color: (u8, u8, u8),
+  qh: &QueueHandle<DimlandData>,
+  shm: &Shm,
+) -> WlBuffer {
+  let mut pool = RawPool::new(4, shm).unwrap();
+  let canvas = pool.mmap();
+
+  let alpha_byte = (alpha * 255.0) as u32;
+  let array: &mut [u8; 4] = canvas.try_into().unwrap();
+  *array = premultiplied_argb(alpha_byte, color).to_le_bytes();
+
+  pool.create_buffer(0, 1, 1, 4, Format::Argb8888, (), qh)
+}
+
+// TODO: corner calc is kinda wrong?
+// see file:///stuff/screenshots/24-05-02T20-36-18.png
+// can't be bothered right now though for it is good enough
+fn create_corner_buffer(
+  alpha: f32,
+  color: (u8, u8, u8),
   radius: u32,
+  corner: Corner,
   qh: &QueueHandle<DimlandData>,
-  width: u32,
-  height: u32,
   shm: &Shm,
 ) -> WlBuffer {
-  let mut pool = RawPool::new(width as usize * height as usize * 4, shm).unwrap();
+  let mut pool = RawPool::new(radius as usize * radius as usize * 4, shm).unwrap();
   let canvas = pool.mmap();
 
-  // TODO: corner calc is kinda wrong?
-  // see file:///stuff/screenshots/24-05-02T20-36-18.png
-  // can't be bothered right now though for it is good enough
-
-  {
-    let corner_radius = radius;
-
-    canvas
-      .chunks_exact_mut(4)
-      .enumerate()
-      .for_each(|(index, chunk)| {
-        let x = (index as u32) % width;
-        let y = (index as u32) / width;
-
-        let mut color = 0x00000000u32;
-        let alpha = (alpha * 255.0) as u32;
-        color |= alpha << 24;
-
-        if (x < corner_radius
-          && y < corner_radius
-          && (corner_radius - x).pow(2) + (corner_radius - y).pow(2) > corner_radius.pow(2))
-          || (x > width - corner_radius
-            && y < corner_radius
-            && (x - (width - corner_radius)).pow(2) + (corner_radius - y).pow(2)
-              > corner_radius.pow(2))
-          || (x < corner_radius
-            && y > height - corner_radius
-            && (corner_radius - x).pow(2) + (y - (height - corner_radius)).pow(2)
-              > corner_radius.pow(2))
-          || (x > width - corner_radius
-            && y > height - corner_radius
-            && (x - (width - corner_radius)).pow(2) + (y - (height - corner_radius)).pow(2)
-              > corner_radius.pow(2))
-        {
-          color = 0xFF000000u32;
-        }
+  let (cx, cy) = match corner {
+    Corner::TopLeft => (radius, radius),
+    Corner::TopRight => (0, radius),
+    Corner::BottomLeft => (radius, 0),
+    Corner::BottomRight => (0, 0),
+  };
 
-        let array: &mut [u8; 4] = chunk.try_into().unwrap();
-        *array = color.to_le_bytes();
-      });
-  }
+  let alpha_byte = (alpha * 255.0) as u32;
+
+  canvas
+    .chunks_exact_mut(4)
+    .enumerate()
+    .for_each(|(index, chunk)| {
+      let x = (index as u32) % radius;
+      let y = (index as u32) / radius;
+
+      let dx = if x > cx { x - cx } else { cx - x };
+      let dy = if y > cy { y - cy } else { cy - y };
+
+      let pixel = if dx.pow(2) + dy.pow(2) > radius.pow(2) {
+        premultiplied_argb(255, color)
+      } else {
+        premultiplied_argb(alpha_byte, color)
+      };
+
+      let array: &mut [u8; 4] = chunk.try_into().unwrap();
+      *array = pixel.to_le_bytes();
+    });
 
   pool.create_buffer(
     0,
-    width as i32,
-    height as i32,
-    width as i32 * 4,
+    radius as i32,
+    radius as i32,
+    radius as i32 * 4,
     Format::Argb8888,
     (),
     qh,
@@ -395,6 +748,8 @@ impl DimlandData {
     layer_shell: LayerShell,
     alpha: f32,
     radius: u32,
+    fade_ms: u32,
+    color: (u8, u8, u8),
     shm: Shm,
   ) -> Self {
     Self {
@@ -404,8 +759,15 @@ impl DimlandData {
       layer_shell,
       viewporter: SimpleGlobal::<wp_viewporter::WpViewporter, 1>::bind(globals, qh)
         .expect("wp_viewporter not available"),
+      subcompositor: SubcompositorState::bind(compositor.wl_compositor().clone(), globals, qh)
+        .expect("wl_subcompositor not available"),
+      fractional_scale_manager: globals
+        .bind::<WpFractionalScaleManagerV1, _, _>(qh, 1..=1, ())
+        .ok(),
       radius,
       alpha,
+      fade_ms,
+      color,
       views: Vec::new(),
       exit: false,
       shm,
@@ -413,7 +775,9 @@ impl DimlandData {
     }
   }
 
-  fn create_view(&self, qh: &QueueHandle<Self>, output: WlOutput) -> DimlandView {
+  // Returns the resolved target alpha alongside the view so the caller can
+  // kick off the startup fade-in once the view has been pushed.
+  fn create_view(&self, qh: &QueueHandle<Self>, output: WlOutput) -> (DimlandView, f32) {
     let layer = self.layer_shell.create_layer_surface(
       qh,
       self.compositor.create_surface(qh),
@@ -422,22 +786,8 @@ impl DimlandData {
       Some(&output),
     );
 
-    let mut alpha = self.alpha;
-    let mut radius = self.radius;
-
-    if let Some(render) = self.output_state.info(&output).and_then(|info| {
-      let args = get_args();
-      if let Some(output) = args.output {
-        return Some(info.name.expect("no output name found") == output);
-      } else {
-        return Some(true);
-      }
-    }) {
-      if !render {
-        alpha = 0.0;
-        radius = 0;
-      }
-    }
+    let output_name = self.output_state.info(&output).and_then(|info| info.name);
+    let (alpha, radius) = self.resolve_output_args(output_name.as_deref());
 
     let (width, height) = if let Some((width, height)) = self
       .output_state
@@ -461,55 +811,256 @@ impl DimlandData {
       .get()
       .expect("wp_viewporter failed")
       .get_viewport(layer.wl_surface(), qh, ());
+    viewport.set_source(0.0, 0.0, 1.0, 1.0);
+
+    let fractional_scale = self
+      .fractional_scale_manager
+      .as_ref()
+      .map(|manager| manager.get_fractional_scale(layer.wl_surface(), qh, layer.wl_surface().clone()));
 
-    let buffer = create_buffer(alpha, radius, qh, width, height, &self.shm);
+    let buffer = create_buffer(0.0, self.color, qh, &self.shm);
+    let corners = self.create_corners(
+      qh,
+      layer.wl_surface(),
+      0.0,
+      radius,
+      width,
+      height,
+      DEFAULT_SCALE_120,
+    );
 
-    DimlandView::new(qh, buffer, viewport, layer, output)
+    (
+      DimlandView::new(
+        qh,
+        buffer,
+        viewport,
+        layer,
+        output,
+        radius,
+        corners,
+        fractional_scale,
+        self.color,
+      ),
+      alpha,
+    )
   }
 
-  fn rerender(&mut self) {
-    for view in &mut self.views {
-      if let Some(rerender) = self.output_state.info(&view.output).and_then(|info| {
-        let args = get_args();
-        if let Some(output) = args.output {
-          return Some(info.name.expect("no output name found") == output);
-        } else {
-          return Some(true);
-        }
-      }) {
-        if rerender {
-          view.buffer = create_buffer(
-            self.alpha,
-            self.radius,
-            self.qh,
-            view.width,
-            view.height,
-            &self.shm,
-          );
-          view.first_configure = true;
-          view.draw(self.qh);
+  #[allow(clippy::too_many_arguments)]
+  fn create_corners(
+    &self,
+    qh: &QueueHandle<Self>,
+    parent: &WlSurface,
+    alpha: f32,
+    radius: u32,
+    width: u32,
+    height: u32,
+    scale_120: u32,
+  ) -> Vec<DimlandCorner> {
+    if radius == 0 || width == 0 || height == 0 {
+      return Vec::new();
+    }
+
+    let device_radius = scale_to_device(radius, scale_120);
+
+    corner_positions(width, height, radius)
+      .into_iter()
+      .map(|(corner, x, y)| {
+        let (subsurface, surface) = self.subcompositor.create_subsurface(parent.clone(), qh);
+        subsurface.set_position(x, y);
+
+        let viewport = self
+          .viewporter
+          .get()
+          .expect("wp_viewporter failed")
+          .get_viewport(&surface, qh, ());
+        viewport.set_destination(radius as i32, radius as i32);
+
+        let buffer = create_corner_buffer(alpha, self.color, device_radius, corner, qh, &self.shm);
+        surface.attach(Some(&buffer), 0, 0);
+        surface.damage(0, 0, device_radius as i32, device_radius as i32);
+        surface.commit();
+
+        DimlandCorner {
+          surface,
+          subsurface,
+          buffer,
+          viewport,
         }
+      })
+      .collect()
+  }
+
+  // Resolves the alpha/radius a given output should render at: its own
+  // `-o NAME:...` override where provided, falling back field-by-field to
+  // the global `--alpha`/`--radius`.
+  fn resolve_output_args(&self, output_name: Option<&str>) -> (f32, u32) {
+    let over = output_name.and_then(get_output_override).unwrap_or_default();
+    (
+      over.alpha.unwrap_or(self.alpha),
+      over.radius.unwrap_or(self.radius),
+    )
+  }
+
+  fn rerender(&mut self) {
+    let qh = self.qh;
+
+    for index in 0..self.views.len() {
+      let output_name = self
+        .output_state
+        .info(&self.views[index].output)
+        .and_then(|info| info.name);
+      let (alpha, radius) = self.resolve_output_args(output_name.as_deref());
+
+      let geometry_changed =
+        self.views[index].radius != radius || self.views[index].rendered_color != self.color;
+      self.views[index].radius = radius;
+
+      if geometry_changed {
+        // Radius and color aren't animated - `start_fade` only kicks in
+        // on an alpha difference, so a radius/color-only change has to
+        // be redrawn here at the view's current alpha or it's silently
+        // dropped whenever the resolved alpha happens to stay the same.
+        let current_alpha = self.views[index].current_alpha;
+        self.apply_alpha(index, current_alpha, qh);
       }
+
+      self.start_fade(index, qh, alpha);
+    }
+  }
+
+  // Rebuilds the center buffer and corner masks for `index` at a given
+  // alpha. Geometry (radius/width/height) is left untouched - only the
+  // alpha channel animates, so this is what both the instant and animated
+  // paths funnel through.
+  fn apply_alpha(&mut self, index: usize, alpha: f32, qh: &QueueHandle<Self>) {
+    let radius = self.views[index].radius;
+    let width = self.views[index].width;
+    let height = self.views[index].height;
+    let scale_120 = self.views[index].scale_120;
+
+    let buffer = create_buffer(alpha, self.color, qh, &self.shm);
+    let corners = self.create_corners(
+      qh,
+      self.views[index].layer.wl_surface(),
+      alpha,
+      radius,
+      width,
+      height,
+      scale_120,
+    );
+
+    let view = &mut self.views[index];
+    view.buffer.destroy();
+    view.buffer = buffer;
+    for corner in view.corners.drain(..) {
+      corner.subsurface.destroy();
+      corner.buffer.destroy();
+      corner.viewport.destroy();
+      corner.surface.destroy();
     }
+    view.corners = corners;
+    view.rendered_color = self.color;
+    view.current_alpha = alpha;
+    view.first_configure = true;
+    view.draw(qh);
+    self.publish_status();
+  }
+
+  // Starts (or retargets) a fade for `index` towards `target`, driven by
+  // frame callbacks requested on the view's surface.
+  fn start_fade(&mut self, index: usize, qh: &QueueHandle<Self>, target: f32) {
+    let duration_ms = self.fade_ms.max(1);
+    let view = &mut self.views[index];
+
+    if (view.current_alpha - target).abs() < f32::EPSILON {
+      view.fade = None;
+      self.publish_status();
+      return;
+    }
+
+    view.fade = Some(FadeState {
+      from: view.current_alpha,
+      to: target,
+      start_time: None,
+      duration_ms,
+    });
+    view.request_frame(qh);
+    self.publish_status();
+  }
+
+  // Refreshes the shared `STATUS` snapshot read by `IpcRequest::Query`.
+  fn publish_status(&self) {
+    let color = format!("{:02x}{:02x}{:02x}", self.color.0, self.color.1, self.color.2);
+
+    let outputs = self
+      .views
+      .iter()
+      .map(|view| OutputStatus {
+        name: self
+          .output_state
+          .info(&view.output)
+          .and_then(|info| info.name)
+          .unwrap_or_else(|| "unknown".to_owned()),
+        alpha: view.current_alpha,
+        radius: view.radius,
+        color: color.clone(),
+        fading: view.fade.is_some(),
+      })
+      .collect();
+
+    *STATUS.lock().unwrap() = DaemonStatus { outputs };
+  }
+
+  // Fades every view down to zero and blocks until they've all reached it,
+  // then cleans up and exits. Used on `Stop` so the overlay fades away
+  // instead of vanishing instantly.
+  fn fade_out_and_exit(&mut self, event_queue: &mut EventQueue<Self>) -> ! {
+    let qh = self.qh;
+
+    for index in 0..self.views.len() {
+      self.start_fade(index, qh, 0.0);
+    }
+
+    loop {
+      event_queue.roundtrip(self).unwrap();
+      if self.views.iter().all(|view| view.fade.is_none()) {
+        break;
+      }
+    }
+
+    cleanup();
+    process::exit(0);
   }
 }
 
 impl DimlandView {
+  #[allow(clippy::too_many_arguments)]
   fn new(
     _qh: &QueueHandle<DimlandData>,
     buffer: WlBuffer,
     viewport: WpViewport,
     layer: LayerSurface,
     output: WlOutput,
+    radius: u32,
+    corners: Vec<DimlandCorner>,
+    fractional_scale: Option<WpFractionalScaleV1>,
+    color: (u8, u8, u8),
   ) -> Self {
     Self {
       first_configure: true,
       width: 0,
       height: 0,
+      radius,
+      rendered_color: color,
+      current_alpha: 0.0,
+      fade: None,
       buffer,
       viewport,
       layer,
       output,
+      corners,
+      scale_120: DEFAULT_SCALE_120,
+      fractional_scale,
     }
   }
 
@@ -527,6 +1078,12 @@ impl DimlandView {
     self.layer.wl_surface().attach(Some(&self.buffer), 0, 0);
     self.layer.commit();
   }
+
+  fn request_frame(&self, qh: &QueueHandle<DimlandData>) {
+    let surface = self.layer.wl_surface();
+    surface.frame(qh, surface.clone());
+    surface.commit();
+  }
 }
 
 impl LayerShellHandler for DimlandData {
@@ -557,6 +1114,15 @@ impl LayerShellHandler for DimlandData {
       .viewport
       .set_destination(view.width as _, view.height as _);
 
+    if view.radius > 0 {
+      for ((_, x, y), corner) in corner_positions(view.width, view.height, view.radius)
+        .into_iter()
+        .zip(view.corners.iter())
+      {
+        corner.subsurface.set_position(x, y);
+      }
+    }
+
     if view.first_configure {
       view.draw(qh);
       view.first_configure = false;
@@ -575,7 +1141,10 @@ impl OutputHandler for DimlandData {
     qh: &QueueHandle<Self>,
     output: smithay_client_toolkit::reexports::client::protocol::wl_output::WlOutput,
   ) {
-    self.views.push(self.create_view(qh, output));
+    let (view, target_alpha) = self.create_view(qh, output);
+    self.views.push(view);
+    let index = self.views.len() - 1;
+    self.start_fade(index, qh, target_alpha);
   }
 
   fn update_output(
@@ -584,11 +1153,13 @@ impl OutputHandler for DimlandData {
     qh: &QueueHandle<Self>,
     output: smithay_client_toolkit::reexports::client::protocol::wl_output::WlOutput,
   ) {
-    let new_view = self.create_view(qh, output);
+    let (new_view, target_alpha) = self.create_view(qh, output);
 
-    if let Some(view) = self.views.iter_mut().find(|v| v.output == new_view.output) {
-      *view = new_view;
-    }
+    let Some(index) = self.views.iter().position(|v| v.output == new_view.output) else {
+      return;
+    };
+    self.views[index] = new_view;
+    self.start_fade(index, qh, target_alpha);
   }
 
   fn output_destroyed(
@@ -598,17 +1169,41 @@ impl OutputHandler for DimlandData {
     output: smithay_client_toolkit::reexports::client::protocol::wl_output::WlOutput,
   ) {
     self.views.retain(|v| v.output != output);
+    self.publish_status();
   }
 }
 
 impl CompositorHandler for DimlandData {
+  // Integer-scale fallback for compositors without wp_fractional_scale_v1.
+  // Ignored for views that already get preferred_scale events, since those
+  // are strictly more precise (and arrive on the same surface).
   fn scale_factor_changed(
     &mut self,
     _conn: &smithay_client_toolkit::reexports::client::Connection,
-    _qh: &QueueHandle<Self>,
-    _surface: &smithay_client_toolkit::reexports::client::protocol::wl_surface::WlSurface,
-    _new_factor: i32,
+    qh: &QueueHandle<Self>,
+    surface: &smithay_client_toolkit::reexports::client::protocol::wl_surface::WlSurface,
+    new_factor: i32,
   ) {
+    let Some(index) = self
+      .views
+      .iter()
+      .position(|view| view.layer.wl_surface() == surface)
+    else {
+      return;
+    };
+
+    if self.views[index].fractional_scale.is_some() {
+      return;
+    }
+
+    let scale_120 = new_factor.max(1) as u32 * DEFAULT_SCALE_120;
+    if self.views[index].scale_120 == scale_120 {
+      return;
+    }
+
+    self.views[index].scale_120 = scale_120;
+    let alpha = self.views[index].current_alpha;
+    self.apply_alpha(index, alpha, qh);
   }
 
   fn transform_changed(
@@ -623,10 +1218,47 @@ impl CompositorHandler for DimlandData {
   fn frame(
     &mut self,
     _conn: &smithay_client_toolkit::reexports::client::Connection,
-    _qh: &QueueHandle<Self>,
-    _surface: &smithay_client_toolkit::reexports::client::protocol::wl_surface::WlSurface,
-    _time: u32,
+    qh: &QueueHandle<Self>,
+    surface: &smithay_client_toolkit::reexports::client::protocol::wl_surface::WlSurface,
+    time: u32,
   ) {
+    let Some(index) = self
+      .views
+      .iter()
+      .position(|view| view.layer.wl_surface() == surface)
+    else {
+      return;
+    };
+
+    let Some(fade) = self.views[index].fade else {
+      return;
+    };
+
+    let start_time = fade.start_time.unwrap_or(time);
+    let elapsed = time.wrapping_sub(start_time);
+    let t = (elapsed as f32 / fade.duration_ms as f32).min(1.0);
+    let done = t >= 1.0;
+    let alpha = if done {
+      fade.to
+    } else {
+      fade.from + (fade.to - fade.from) * ease_out_cubic(t)
+    };
+
+    self.apply_alpha(index, alpha, qh);
+
+    if done {
+      self.views[index].fade = None;
+      // `apply_alpha` just published a snapshot with `fading: true` for
+      // this view - refresh it now that the fade has actually ended, or
+      // a `Query` right after this frame would report a stale fade.
+      self.publish_status();
+    } else {
+      self.views[index].fade = Some(FadeState {
+        start_time: Some(start_time),
+        ..fade
+      });
+      self.views[index].request_frame(qh);
+    }
   }
 }
 
@@ -685,5 +1317,69 @@ impl Drop for DimlandView {
   fn drop(&mut self) {
     self.viewport.destroy();
     self.buffer.destroy();
+    if let Some(fractional_scale) = &self.fractional_scale {
+      fractional_scale.destroy();
+    }
+    for corner in &self.corners {
+      corner.subsurface.destroy();
+      corner.buffer.destroy();
+      corner.viewport.destroy();
+      corner.surface.destroy();
+    }
+  }
+}
+
+impl Dispatch<WpFractionalScaleManagerV1, ()> for DimlandData {
+  fn event(
+    _: &mut Self,
+    _: &WpFractionalScaleManagerV1,
+    _: wp_fractional_scale_manager_v1::Event,
+    _: &(),
+    _: &Connection,
+    _: &QueueHandle<Self>,
+  ) {
+  }
+}
+
+impl Dispatch<WpFractionalScaleV1, WlSurface> for DimlandData {
+  fn event(
+    state: &mut Self,
+    _: &WpFractionalScaleV1,
+    event: wp_fractional_scale_v1::Event,
+    surface: &WlSurface,
+    _: &Connection,
+    qh: &QueueHandle<Self>,
+  ) {
+    let wp_fractional_scale_v1::Event::PreferredScale { scale } = event else {
+      return;
+    };
+
+    let Some(index) = state
+      .views
+      .iter()
+      .position(|view| view.layer.wl_surface() == surface)
+    else {
+      return;
+    };
+
+    if state.views[index].scale_120 == scale {
+      return;
+    }
+
+    state.views[index].scale_120 = scale;
+    let alpha = state.views[index].current_alpha;
+    state.apply_alpha(index, alpha, qh);
+  }
+}
+
+impl Dispatch<WlSubsurface, ()> for DimlandData {
+  fn event(
+    _: &mut Self,
+    _: &WlSubsurface,
+    _: wl_subsurface::Event,
+    _: &(),
+    _: &Connection,
+    _: &QueueHandle<Self>,
+  ) {
   }
 }